@@ -14,6 +14,7 @@ use anyhow::Result;
 use sway_core::{
     decl_engine::*,
     language::ty::{self, TyTraitFn, TyTraitInterfaceItem},
+    transform::{AttributeKind, AttributesMap},
     Engines, TypeInfo,
 };
 use sway_types::{integer_bits::IntegerBits, Ident};
@@ -36,6 +37,16 @@ pub(crate) enum Descriptor {
     NonDocumentable,
 }
 
+/// Returns `true` if the item is annotated with `#[doc(hidden)]` and should
+/// be omitted from generated documentation regardless of its visibility.
+fn is_doc_hidden(attributes: &AttributesMap) -> bool {
+    attributes.get(&AttributeKind::Doc).is_some_and(|attrs| {
+        attrs
+            .iter()
+            .any(|attr| attr.args.iter().any(|arg| arg.name.as_str() == "hidden"))
+    })
+}
+
 impl Descriptor {
     /// Decides whether a [ty::TyDecl] is [Descriptor::Documentable] and returns a [Document] if so.
     pub(crate) fn from_typed_decl(
@@ -48,7 +59,9 @@ impl Descriptor {
         match ty_decl {
             ty::TyDecl::StructDecl(ty::StructDecl { decl_id, .. }) => {
                 let struct_decl = decl_engine.get_struct(decl_id);
-                if !document_private_items && struct_decl.visibility.is_private() {
+                if is_doc_hidden(&struct_decl.attributes)
+                    || (!document_private_items && struct_decl.visibility.is_private())
+                {
                     Ok(Descriptor::NonDocumentable)
                 } else {
                     let item_name = struct_decl.call_path.suffix.clone();
@@ -85,7 +98,9 @@ impl Descriptor {
             }
             ty::TyDecl::EnumDecl(ty::EnumDecl { decl_id, .. }) => {
                 let enum_decl = decl_engine.get_enum(decl_id);
-                if !document_private_items && enum_decl.visibility.is_private() {
+                if is_doc_hidden(&enum_decl.attributes)
+                    || (!document_private_items && enum_decl.visibility.is_private())
+                {
                     Ok(Descriptor::NonDocumentable)
                 } else {
                     let item_name = enum_decl.call_path.suffix.clone();
@@ -122,7 +137,9 @@ impl Descriptor {
             }
             ty::TyDecl::TraitDecl(ty::TraitDecl { decl_id, .. }) => {
                 let trait_decl = (*decl_engine.get_trait(decl_id)).clone();
-                if !document_private_items && trait_decl.visibility.is_private() {
+                if is_doc_hidden(&trait_decl.attributes)
+                    || (!document_private_items && trait_decl.visibility.is_private())
+                {
                     Ok(Descriptor::NonDocumentable)
                 } else {
                     let item_name = trait_decl.name;
@@ -170,6 +187,9 @@ impl Descriptor {
             }
             ty::TyDecl::AbiDecl(ty::AbiDecl { decl_id, .. }) => {
                 let abi_decl = (*decl_engine.get_abi(decl_id)).clone();
+                if is_doc_hidden(&abi_decl.attributes) {
+                    return Ok(Descriptor::NonDocumentable);
+                }
                 let item_name = abi_decl.name;
                 let attrs_opt =
                     (!abi_decl.attributes.is_empty()).then(|| abi_decl.attributes.to_html_string());
@@ -211,6 +231,9 @@ impl Descriptor {
             }
             ty::TyDecl::StorageDecl(ty::StorageDecl { decl_id, .. }) => {
                 let storage_decl = decl_engine.get_storage(decl_id);
+                if is_doc_hidden(&storage_decl.attributes) {
+                    return Ok(Descriptor::NonDocumentable);
+                }
                 let item_name = sway_types::BaseIdent::new_no_trim(
                     sway_types::span::Span::from_string(CONTRACT_STORAGE.to_string()),
                 );
@@ -246,7 +269,9 @@ impl Descriptor {
             }
             ty::TyDecl::FunctionDecl(ty::FunctionDecl { decl_id, .. }) => {
                 let fn_decl = decl_engine.get_function(decl_id);
-                if !document_private_items && fn_decl.visibility.is_private() {
+                if is_doc_hidden(&fn_decl.attributes)
+                    || (!document_private_items && fn_decl.visibility.is_private())
+                {
                     Ok(Descriptor::NonDocumentable)
                 } else {
                     let item_name = fn_decl.name.clone();
@@ -279,7 +304,9 @@ impl Descriptor {
             }
             ty::TyDecl::ConstantDecl(ty::ConstantDecl { decl_id, .. }) => {
                 let const_decl = decl_engine.get_constant(decl_id);
-                if !document_private_items && const_decl.visibility.is_private() {
+                if is_doc_hidden(&const_decl.attributes)
+                    || (!document_private_items && const_decl.visibility.is_private())
+                {
                     Ok(Descriptor::NonDocumentable)
                 } else {
                     let item_name = const_decl.call_path.suffix.clone();
@@ -369,3 +396,40 @@ impl Descriptor {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use indexmap::IndexMap;
+    use std::sync::Arc;
+    use sway_core::transform::{Attribute, AttributeArg};
+    use sway_types::Span;
+
+    fn doc_attributes(arg_name: &str) -> AttributesMap {
+        let mut attrs = IndexMap::new();
+        attrs.insert(
+            AttributeKind::Doc,
+            vec![Attribute {
+                name: Ident::new_no_span("doc".into()),
+                args: vec![AttributeArg {
+                    name: Ident::new_no_span(arg_name.into()),
+                    value: None,
+                    span: Span::dummy(),
+                }],
+                span: Span::dummy(),
+            }],
+        );
+        AttributesMap::new(Arc::new(attrs))
+    }
+
+    #[test]
+    fn doc_hidden_attribute_is_detected() {
+        assert!(is_doc_hidden(&doc_attributes("hidden")));
+    }
+
+    #[test]
+    fn other_doc_attributes_are_not_hidden() {
+        assert!(!is_doc_hidden(&doc_attributes("inline")));
+        assert!(!is_doc_hidden(&AttributesMap::default()));
+    }
+}
@@ -447,7 +447,53 @@ pub fn create_diagnostics_renderer() -> Renderer {
         )
 }
 
+/// Returns a machine-readable JSON representation of a compiler diagnostic.
+///
+/// Diagnostics are printed in this format, one JSON object per line, when the
+/// [constants::FORC_DIAGNOSTICS_FORMAT] environment variable is set to `json`.
+///
+/// The payload is stable enough for tools (editors, CI annotators) to consume:
+/// level, optional reason code and description, the issue message, and one
+/// entry per label with its path and zero-based byte span plus one-based
+/// line/column range.
+pub fn diagnostic_to_json(diagnostic: &Diagnostic) -> serde_json::Value {
+    fn label_to_json(label: &Label) -> serde_json::Value {
+        let LineColRange { start, end } = label.span().line_col();
+        serde_json::json!({
+            "type": format!("{:?}", label.label_type()).to_lowercase(),
+            "path": label.source_path().map(|path| path.as_str()),
+            "start": { "line": start.line, "col": start.col, "byte": label.span().start() },
+            "end": { "line": end.line, "col": end.col, "byte": label.span().end() },
+            "text": label.text(),
+        })
+    }
+
+    serde_json::json!({
+        "level": format!("{:?}", diagnostic.level()).to_lowercase(),
+        "code": diagnostic.reason().map(|reason| reason.code()),
+        "reason": diagnostic.reason().map(|reason| reason.description()),
+        "message": diagnostic.issue().text(),
+        "labels": diagnostic
+            .labels()
+            .iter()
+            .map(|label| label_to_json(label))
+            .collect::<Vec<_>>(),
+        "help": diagnostic.help().collect::<Vec<_>>(),
+    })
+}
+
 fn format_diagnostic(diagnostic: &Diagnostic) {
+    // Tools that want to consume diagnostics programmatically can set
+    // `FORC_DIAGNOSTICS_FORMAT=json` to get newline-delimited JSON instead
+    // of the human-readable rendering. See [diagnostic_to_json] for the shape.
+    if std::env::var_os(constants::FORC_DIAGNOSTICS_FORMAT).is_some_and(|format| format == "json") {
+        match diagnostic.level() {
+            Level::Warning => tracing::warn!("{}", diagnostic_to_json(diagnostic)),
+            Level::Error => tracing::error!("{}", diagnostic_to_json(diagnostic)),
+        }
+        return;
+    }
+
     /// Temporary switch for testing the feature.
     /// Keep it false until we decide to fully support the diagnostic codes.
     const SHOW_DIAGNOSTIC_CODE: bool = false;
@@ -817,3 +863,50 @@ fn ok_construct_window() {
     assert_eq!(t(10, 1, 10, 1, 14, 15, eight_lines), (0, 1, "8"));
     assert_eq!(t(11, 1, 11, 1, 14, 15, eight_lines), (0, 0, ""));
 }
+
+#[test]
+fn ok_diagnostic_to_json() {
+    use serde_json::json;
+    use sway_error::error::CompileError;
+    use sway_types::{Ident, SourceEngine};
+
+    let source_engine = SourceEngine::default();
+    let source_id = source_engine.get_source_id(&PathBuf::from("main.sw"));
+    let src: std::sync::Arc<str> = std::sync::Arc::from("let a = baz;");
+    let span = Span::new(src, 8, 11, Some(source_id)).unwrap();
+
+    let error = CompileError::SymbolNotFound {
+        name: Ident::new_with_override("baz".into(), span.clone()),
+        span,
+        did_you_mean: Some("bar".into()),
+        import_candidates: vec!["utils::baz".into()],
+    };
+
+    let diagnostic = error.to_diagnostic(&source_engine);
+    let json = diagnostic_to_json(&diagnostic);
+
+    assert_eq!(json["level"], json!("error"));
+    assert_eq!(json["reason"], json!("Symbol is not found in scope"));
+    assert_eq!(
+        json["message"],
+        json!("Could not find symbol \"baz\" in this scope.")
+    );
+    assert_eq!(
+        json["help"],
+        json!(["Consider importing it: `use utils::baz;`."])
+    );
+
+    let labels = json["labels"].as_array().unwrap();
+    assert_eq!(labels.len(), 2);
+    assert_eq!(labels[0]["type"], json!("error"));
+    assert_eq!(
+        labels[0]["start"],
+        json!({ "line": 1, "col": 9, "byte": 8 })
+    );
+    assert_eq!(
+        labels[0]["end"],
+        json!({ "line": 1, "col": 12, "byte": 11 })
+    );
+    assert_eq!(labels[1]["type"], json!("help"));
+    assert_eq!(labels[1]["text"], json!("Did you mean \"bar\"?"));
+}
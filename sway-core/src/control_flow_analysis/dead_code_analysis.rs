@@ -801,7 +801,17 @@ fn connect_impl_trait<'eng: 'cfg, 'cfg>(
                             true
                         }
                     } else {
-                        matches!(tree_type, TreeType::Library { .. } | TreeType::Contract)
+                        // In contracts, only methods implementing the ABI surface are
+                        // implicitly reachable. Other impl methods must be reachable from
+                        // an entry point, otherwise they are reported as dead code.
+                        match tree_type {
+                            TreeType::Library { .. } => true,
+                            TreeType::Contract => matches!(
+                                trait_decl_ref,
+                                Some(decl_ref) if matches!(decl_ref.id(), InterfaceDeclId::Abi(_))
+                            ),
+                            _ => false,
+                        }
                     };
                 if add_edge_to_fn_decl {
                     graph.add_edge(entry_node, fn_decl_entry_node, "".into());
@@ -1,6 +1,9 @@
 use std::sync::Arc;
 
-use sway_error::handler::{ErrorEmitted, Handler};
+use sway_error::{
+    handler::{ErrorEmitted, Handler},
+    warning::{CompileWarning, Warning},
+};
 use sway_types::Span;
 
 use crate::{
@@ -99,6 +102,21 @@ impl TyModule {
         }
     }
 
+    /// All declarations within this module, without entering submodules.
+    ///
+    /// Intended for tools and code generators that need to enumerate all
+    /// declarations of a given kind; filter the result on the [TyDecl]
+    /// variant of interest.
+    pub fn decls(&self) -> impl '_ + Iterator<Item = TyDecl> {
+        self.all_nodes.iter().filter_map(|node| {
+            if let TyAstNodeContent::Declaration(decl) = &node.content {
+                Some(decl.clone())
+            } else {
+                None
+            }
+        })
+    }
+
     /// All test functions within this module.
     pub fn test_fns<'a: 'b, 'b>(
         &'b self,
@@ -145,7 +163,33 @@ impl TyModule {
         handler: &Handler,
         allow_deprecated: &mut AllowDeprecatedState,
     ) {
-        for (_, submodule) in self.submodules.iter() {
+        for (mod_name, submodule) in self.submodules.iter() {
+            // A submodule can be deprecated as a whole via an inner
+            // `#![deprecated]` attribute. Warn at the module declaration site
+            // so sunsetting a module points users at the `mod` statement.
+            if !allow_deprecated.is_allowed() {
+                if let Some(attr) = submodule
+                    .module
+                    .attributes
+                    .get(&transform::AttributeKind::Deprecated)
+                    .and_then(|attrs| attrs.last())
+                {
+                    let mut message = format!("deprecated module \"{mod_name}\"");
+                    if let Some(sway_ast::Literal::String(s)) = attr
+                        .args
+                        .iter()
+                        .find(|arg| arg.name.as_str() == "note")
+                        .and_then(|arg| arg.value.as_ref())
+                    {
+                        message.push_str(": ");
+                        message.push_str(s.parsed.as_str());
+                    }
+                    handler.emit_warn(CompileWarning {
+                        span: submodule.mod_name_span.clone(),
+                        warning_content: Warning::UsingDeprecated { message },
+                    });
+                }
+            }
             submodule
                 .module
                 .check_deprecated(engines, handler, allow_deprecated);
@@ -225,6 +225,29 @@ impl TyProgram {
                     }
                 }
 
+                // With encoding v0, contract calls are dispatched on the
+                // four-byte truncated selector hash, so two ABI methods whose
+                // selectors clash would make one of them unreachable. Detect
+                // that here rather than at dispatch time.
+                if !experimental.new_encoding {
+                    let mut selectors = Vec::with_capacity(abi_entries.len());
+                    for decl_id in abi_entries.iter() {
+                        let fn_decl = decl_engine.get_function(decl_id);
+                        if let Ok(selector) = fn_decl.to_fn_selector_value(handler, engines) {
+                            selectors.push((selector, fn_decl.name.clone()));
+                        }
+                    }
+                    for (method_name, other_method_name) in
+                        find_function_selector_clashes(selectors)
+                    {
+                        handler.emit_err(CompileError::FunctionSelectorClash {
+                            span: method_name.span(),
+                            method_name,
+                            other_method_name,
+                        });
+                    }
+                }
+
                 TyProgramKind::Contract {
                     entry_function: if experimental.new_encoding {
                         if entries.len() != 1 {
@@ -423,6 +446,18 @@ impl TyProgram {
         Ok((typed_program_kind, declarations, configurables))
     }
 
+    /// All declarations within the program, including submodules.
+    ///
+    /// Intended for tools and code generators that need to enumerate all
+    /// declarations of a given kind; filter the result on the [TyDecl]
+    /// variant of interest.
+    pub fn decls(&self) -> impl '_ + Iterator<Item = TyDecl> {
+        self.root
+            .submodules_recursive()
+            .flat_map(|(_, submod)| submod.module.decls())
+            .chain(self.root.decls())
+    }
+
     /// All test function declarations within the program.
     pub fn test_fns<'a: 'b, 'b>(
         &'b self,
@@ -606,3 +641,37 @@ fn disallow_impure_functions(
     errs.append(&mut err_purity);
     errs
 }
+
+/// Pairs every ABI method whose four-byte selector is already taken with the
+/// method, earlier in declaration order, that took it.
+fn find_function_selector_clashes(selectors: Vec<([u8; 4], Ident)>) -> Vec<(Ident, Ident)> {
+    let mut seen: std::collections::HashMap<[u8; 4], Ident> = std::collections::HashMap::new();
+    let mut clashes = vec![];
+    for (selector, method_name) in selectors {
+        match seen.get(&selector) {
+            Some(other_method_name) => clashes.push((method_name, other_method_name.clone())),
+            None => {
+                seen.insert(selector, method_name);
+            }
+        }
+    }
+    clashes
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn finds_function_selector_clashes() {
+        let method = |selector, name: &str| (selector, Ident::new_no_span(name.into()));
+        let clashes = find_function_selector_clashes(vec![
+            method([0x05, 0x71, 0xda, 0x48], "clash_7451"),
+            method([0x00, 0x00, 0x00, 0x01], "unrelated"),
+            method([0x05, 0x71, 0xda, 0x48], "clash_8716"),
+        ]);
+        assert_eq!(clashes.len(), 1);
+        assert_eq!(clashes[0].0.as_str(), "clash_8716");
+        assert_eq!(clashes[0].1.as_str(), "clash_7451");
+    }
+}
@@ -49,7 +49,7 @@ use sway_ir::{
     FN_DEDUP_DEBUG_PROFILE_NAME, FN_INLINE_NAME, MEM2REG_NAME, MEMCPYOPT_NAME, MISC_DEMOTION_NAME,
     RET_DEMOTION_NAME, SIMPLIFY_CFG_NAME, SROA_NAME,
 };
-use sway_types::constants::DOC_COMMENT_ATTRIBUTE_NAME;
+use sway_types::constants::{DEPRECATED_ATTRIBUTE_NAME, DOC_COMMENT_ATTRIBUTE_NAME};
 use sway_types::SourceEngine;
 use sway_utils::{time_expr, PerformanceData, PerformanceMetric};
 use transform::{Attribute, AttributeArg, AttributeKind, AttributesMap};
@@ -66,6 +66,20 @@ pub use language::Programs;
 use language::{lexed, parsed, ty, Visibility};
 use transform::to_parsed_lang::{self, convert_module_kind};
 
+/// The version of this compiler crate, as recorded in its manifest.
+///
+/// Exposed so that external tooling (build orchestrators, language servers,
+/// registries) can report or adapt to the compiler release it is driving
+/// without parsing `forc --version` output.
+pub const VERSION: &str = env!("CARGO_PKG_VERSION");
+
+/// The version of the Fuel VM this compiler release targets, i.e. the version
+/// of the `fuel-vm` crate the emitted bytecode is built against.
+///
+/// `fuel-vm` does not export its own version, so this mirrors the dependency
+/// pinned in the workspace manifest; the two must be updated together.
+pub const FUEL_VM_VERSION: &str = "0.58";
+
 pub mod fuel_prelude {
     pub use fuel_vm::{self, fuel_asm, fuel_crypto, fuel_tx, fuel_types};
 }
@@ -154,8 +168,8 @@ fn module_attrs_to_map(
         let attrs = attr_decl.attribute.get().into_iter();
         for attr in attrs {
             let name = attr.name.as_str();
-            if name != DOC_COMMENT_ATTRIBUTE_NAME {
-                // prevent using anything except doc comment attributes
+            if name != DOC_COMMENT_ATTRIBUTE_NAME && name != DEPRECATED_ATTRIBUTE_NAME {
+                // prevent using anything except doc comment and deprecated attributes
                 handler.emit_err(CompileError::ExpectedModuleDocComment {
                     span: attr.name.span(),
                 });
@@ -186,6 +200,7 @@ fn module_attrs_to_map(
 
             if let Some(attr_kind) = match name {
                 DOC_COMMENT_ATTRIBUTE_NAME => Some(AttributeKind::DocComment),
+                DEPRECATED_ATTRIBUTE_NAME => Some(AttributeKind::Deprecated),
                 _ => None,
             } {
                 attrs_map.entry(attr_kind).or_default().push(attribute);
@@ -507,8 +522,8 @@ pub(crate) fn is_parse_module_cache_up_to_date(
                 },
             );
 
-        // Checks if the typed module cache for a given path is up to date// If the cache is up to date, recursively check all dependencies to make sure they have not been
-        // modified either.
+        // If the cache is up to date, recursively check all dependencies to make sure they have
+        // not been modified either.
         cache_up_to_date
             && entry.common.dependencies.iter().all(|dep_path| {
                 is_parse_module_cache_up_to_date(engines, dep_path, include_tests, build_config)
@@ -1,3 +1,12 @@
+//! Per-module caching for incremental compilation.
+//!
+//! Parsed and typed representations of modules are cached keyed by module path
+//! (see [`ModuleCacheKey`]), and invalidated via source content hashes and
+//! modification times, or via LSP file versions when a build is driven by the
+//! language server. Callers consult the cache through
+//! `is_parse_module_cache_up_to_date` and `is_ty_module_cache_up_to_date` in
+//! the crate root before re-running a compilation stage.
+
 use parking_lot::{RwLock, RwLockReadGuard, RwLockWriteGuard};
 use std::{
     collections::HashMap,
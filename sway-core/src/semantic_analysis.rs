@@ -15,6 +15,7 @@ mod type_check_finalization;
 pub(crate) mod type_resolve;
 pub use ast_node::*;
 pub use namespace::Namespace;
+pub use node_dependencies::{decl_dependencies, order_ast_nodes_by_dependency};
 pub(crate) use type_check_analysis::*;
 pub(crate) use type_check_context::TypeCheckContext;
 pub(crate) use type_check_finalization::*;
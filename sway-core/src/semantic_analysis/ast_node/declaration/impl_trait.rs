@@ -21,7 +21,10 @@ use crate::{
         },
         *,
     },
-    namespace::{IsExtendingExistingImpl, IsImplSelf, TryInsertingTraitImplOnFailure},
+    namespace::{
+        did_you_mean, IsExtendingExistingImpl, IsImplSelf, ResolvedDeclaration,
+        TryInsertingTraitImplOnFailure,
+    },
     semantic_analysis::{
         symbol_collection_context::SymbolCollectionContext, AbiMode, ConstShadowingMode,
         TyNodeDepGraphNodeId, TypeCheckAnalysis, TypeCheckAnalysisContext, TypeCheckContext,
@@ -307,9 +310,30 @@ impl TyImplSelfOrTrait {
                         }
                     }
                     Some(_) | None => {
+                        // Suggest a similarly named trait or ABI in scope, if there is one.
+                        let did_you_mean = did_you_mean(
+                            &trait_name.suffix,
+                            ctx.namespace()
+                                .module(engines)
+                                .current_items()
+                                .symbols()
+                                .iter()
+                                .filter(|(_, decl)| match decl {
+                                    ResolvedDeclaration::Parsed(decl) => matches!(
+                                        decl,
+                                        Declaration::TraitDeclaration(_)
+                                            | Declaration::AbiDeclaration(_)
+                                    ),
+                                    ResolvedDeclaration::Typed(decl) => {
+                                        matches!(decl, TyDecl::TraitDecl(_) | TyDecl::AbiDecl(_))
+                                    }
+                                })
+                                .map(|(name, _)| name),
+                        );
                         return Err(handler.emit_err(CompileError::UnknownTrait {
                             name: trait_name.suffix.clone(),
                             span: trait_name.span(),
+                            did_you_mean,
                         }));
                     }
                 };
@@ -1141,6 +1165,9 @@ fn type_check_impl_method(
         None => {
             return Err(
                 handler.emit_err(CompileError::FunctionNotAPartOfInterfaceSurface {
+                    // Suggest a similarly named method from the interface surface,
+                    // if there is one.
+                    did_you_mean: did_you_mean(&impl_method.name, method_checklist.keys()),
                     name: impl_method.name.clone(),
                     interface_name: interface_name(),
                     span: impl_method.name.span(),
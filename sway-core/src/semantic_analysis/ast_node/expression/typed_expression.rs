@@ -18,7 +18,10 @@ pub(crate) use self::{
 };
 
 use crate::{
-    asm_lang::{virtual_ops::VirtualOp, virtual_register::VirtualRegister},
+    asm_lang::{
+        virtual_ops::VirtualOp,
+        virtual_register::{ConstantRegister, VirtualRegister},
+    },
     decl_engine::*,
     language::{
         parsed::*,
@@ -1701,6 +1704,8 @@ impl ty::TyExpression {
                 return Err(handler.emit_err(CompileError::SymbolNotFound {
                     name: unknown_call_path_binding.inner.call_path.suffix.clone(),
                     span: unknown_call_path_binding.inner.call_path.suffix.span(),
+                    did_you_mean: None,
+                    import_candidates: vec![],
                 }));
             }
             _ => {
@@ -2750,7 +2755,47 @@ fn check_asm_block_validity(
         ));
     }
 
-    // Check #1: Disallow control flow instructions
+    // Check #1: Check that the opcodes and the return register only reference
+    // registers that are either declared in the asm block's register list or
+    // are VM-reserved registers. Previously this was only caught during asm
+    // generation; checking it here reports the error during type checking,
+    // together with all other asm block constraint violations.
+    //
+    let declared_registers = asm
+        .registers
+        .iter()
+        .map(|reg| reg.name.as_str())
+        .collect::<FxHashSet<_>>();
+    let is_known_register = |name: &str| {
+        declared_registers.contains(name) || ConstantRegister::parse_register_name(name).is_some()
+    };
+    let declared_registers_list = || {
+        asm.registers
+            .iter()
+            .map(|reg| reg.name.to_string())
+            .collect::<Vec<_>>()
+            .join("\n")
+    };
+    for op in &asm.body {
+        for reg_name in &op.op_args {
+            if !is_known_register(reg_name.as_str()) {
+                handler.emit_err(CompileError::UnknownRegister {
+                    span: reg_name.span(),
+                    initialized_registers: declared_registers_list(),
+                });
+            }
+        }
+    }
+    if let Some((return_reg, return_reg_span)) = &asm.returns {
+        if !is_known_register(return_reg.name.as_str()) {
+            handler.emit_err(CompileError::UnknownRegister {
+                span: return_reg_span.clone(),
+                initialized_registers: declared_registers_list(),
+            });
+        }
+    }
+
+    // Check #2: Disallow control flow instructions
     //
     for err in opcodes.iter().filter_map(|op| {
         if matches!(
@@ -2775,7 +2820,7 @@ fn check_asm_block_validity(
         handler.emit_err(err);
     }
 
-    // Check #2: Disallow initialized registers from being reassigned in the asm block
+    // Check #3: Disallow initialized registers from being reassigned in the asm block
     //
     // 1. Collect all registers that have initializers in the list of arguments
     let initialized_registers = asm
@@ -2821,7 +2866,7 @@ fn check_asm_block_validity(
         handler.emit_err(err);
     }
 
-    // Check #3: Check if there are uninitialized registers that are read before being written
+    // Check #4: Check if there are uninitialized registers that are read before being written
     let mut uninitialized_registers = asm
         .registers
         .iter()
@@ -197,6 +197,14 @@ impl Items {
         Err(handler.emit_err(CompileError::SymbolNotFound {
             name: symbol.clone(),
             span: symbol.span(),
+            did_you_mean: did_you_mean(
+                symbol,
+                self.symbols
+                    .keys()
+                    .chain(self.use_item_synonyms.keys())
+                    .chain(self.use_glob_synonyms.keys()),
+            ),
+            import_candidates: vec![],
         }))
     }
 
@@ -745,6 +753,8 @@ impl Items {
             .ok_or_else(|| CompileError::SymbolNotFound {
                 name: name.clone(),
                 span: name.span(),
+                did_you_mean: did_you_mean(name, self.symbols.keys()),
+                import_candidates: vec![],
             })
     }
 
@@ -759,6 +769,8 @@ impl Items {
             .ok_or_else(|| CompileError::SymbolNotFound {
                 name: name.clone(),
                 span: name.span(),
+                did_you_mean: None,
+                import_candidates: vec![],
             })
     }
 
@@ -1013,3 +1025,37 @@ fn get_path_for_decl(
     }
     path_names.join("::")
 }
+
+/// Returns the name from `candidates` closest to `symbol` by edit distance,
+/// if one is similar enough to be worth suggesting in a "Did you mean" hint.
+pub(crate) fn did_you_mean<'a, I>(symbol: &Ident, candidates: I) -> Option<String>
+where
+    I: Iterator<Item = &'a Ident>,
+{
+    let symbol = symbol.as_str();
+    // Allow roughly one edit per three characters, but always at least one.
+    let threshold = std::cmp::max(1, symbol.len() / 3);
+    candidates
+        .filter(|candidate| candidate.as_str() != symbol)
+        .map(|candidate| (levenshtein_distance(symbol, candidate.as_str()), candidate))
+        .filter(|(distance, _)| *distance <= threshold)
+        // Break ties on the name itself to keep the suggestion deterministic.
+        .min_by_key(|(distance, candidate)| (*distance, candidate.as_str()))
+        .map(|(_, candidate)| candidate.to_string())
+}
+
+fn levenshtein_distance(a: &str, b: &str) -> usize {
+    let a: Vec<char> = a.chars().collect();
+    let b: Vec<char> = b.chars().collect();
+    let mut prev: Vec<usize> = (0..=b.len()).collect();
+    let mut cur: Vec<usize> = vec![0; b.len() + 1];
+    for (i, a_char) in a.iter().enumerate() {
+        cur[0] = i + 1;
+        for (j, b_char) in b.iter().enumerate() {
+            let cost = usize::from(a_char != b_char);
+            cur[j + 1] = std::cmp::min(std::cmp::min(prev[j + 1] + 1, cur[j] + 1), prev[j] + cost);
+        }
+        std::mem::swap(&mut prev, &mut cur);
+    }
+    prev[b.len()]
+}
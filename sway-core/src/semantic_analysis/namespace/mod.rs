@@ -8,6 +8,7 @@ mod submodule_namespace;
 mod trait_map;
 
 pub use contract_helpers::*;
+pub(crate) use lexical_scope::did_you_mean;
 pub use lexical_scope::{Items, LexicalScope, LexicalScopeId, LexicalScopePath};
 pub use module::Module;
 pub use namespace::Namespace;
@@ -331,6 +331,8 @@ impl Root {
             return Err(handler.emit_err(CompileError::SymbolNotFound {
                 name: item.clone(),
                 span: item.span(),
+                did_you_mean: None,
+                import_candidates: vec![],
             }));
         };
 
@@ -516,6 +518,8 @@ impl Root {
                         return Err(handler.emit_err(CompileError::SymbolNotFound {
                             name: variant_name.clone(),
                             span: variant_name.span(),
+                            did_you_mean: None,
+                            import_candidates: vec![],
                         }));
                     }
                 }
@@ -584,6 +588,8 @@ impl Root {
                         return Err(handler.emit_err(CompileError::SymbolNotFound {
                             name: variant_name.clone(),
                             span: variant_name.span(),
+                            did_you_mean: None,
+                            import_candidates: vec![],
                         }));
                     }
                 } else {
@@ -878,12 +884,90 @@ impl Root {
         self.module
             .lookup_submodule(handler, engines, mod_path)
             .and_then(|module| {
-                let decl = module
+                let lookup_handler = Handler::default();
+                match module.current_lexical_scope().items.resolve_symbol(
+                    &lookup_handler,
+                    engines,
+                    symbol,
+                ) {
+                    Ok(decl) => {
+                        handler.append(lookup_handler);
+                        Ok((decl, mod_path.to_vec()))
+                    }
+                    Err(err) => {
+                        // The symbol is not in scope. If it is declared in
+                        // other modules, suggest importing it from there.
+                        let (errors, warnings) = lookup_handler.consume();
+                        for warning in warnings {
+                            handler.emit_warn(warning);
+                        }
+                        for error in errors {
+                            handler.emit_err(match error {
+                                CompileError::SymbolNotFound {
+                                    name,
+                                    span,
+                                    did_you_mean,
+                                    ..
+                                } => CompileError::SymbolNotFound {
+                                    import_candidates: self.find_import_candidates(engines, &name),
+                                    name,
+                                    span,
+                                    did_you_mean,
+                                },
+                                error => error,
+                            });
+                        }
+                        Err(err)
+                    }
+                }
+            })
+    }
+
+    /// Collects the full paths of all modules in the program, including
+    /// external ones, that declare a public symbol named `symbol`. The paths
+    /// are suggested as `use` candidates when symbol resolution fails, so
+    /// private declarations and modules that cannot be imported from are
+    /// skipped.
+    fn find_import_candidates(&self, engines: &Engines, symbol: &Ident) -> Vec<String> {
+        fn recurse(
+            module: &Module,
+            engines: &Engines,
+            path: &mut Vec<String>,
+            symbol: &Ident,
+            candidates: &mut Vec<String>,
+        ) {
+            if !path.is_empty()
+                && module
                     .current_lexical_scope()
                     .items
-                    .resolve_symbol(handler, engines, symbol)?;
-                Ok((decl, mod_path.to_vec()))
-            })
+                    .symbols()
+                    .get(symbol)
+                    .is_some_and(|decl| decl.visibility(engines).is_public())
+            {
+                candidates.push(format!("{}::{}", path.join("::"), symbol));
+            }
+            for (name, submodule) in module.submodules() {
+                // Direct children are always accessible, deeper submodules
+                // only through a public `mod`.
+                if !path.is_empty() && submodule.visibility().is_private() {
+                    continue;
+                }
+                path.push(name.clone());
+                recurse(submodule, engines, path, symbol, candidates);
+                path.pop();
+            }
+        }
+
+        let mut candidates = Vec::new();
+        recurse(
+            &self.module,
+            engines,
+            &mut Vec::new(),
+            symbol,
+            &mut candidates,
+        );
+        candidates.sort();
+        candidates
     }
 
     #[allow(clippy::too_many_arguments)]
@@ -954,6 +1038,8 @@ impl Root {
                     return Err(handler.emit_err(CompileError::SymbolNotFound {
                         name: symbol.clone(),
                         span: symbol.span(),
+                        did_you_mean: None,
+                        import_candidates: vec![],
                     }))
                 }
             }),
@@ -1310,6 +1310,8 @@ impl TraitMap {
             Ordering::Less => Err(handler.emit_err(CompileError::SymbolNotFound {
                 name: symbol.clone(),
                 span: symbol.span(),
+                did_you_mean: None,
+                import_candidates: vec![],
             })),
             Ordering::Equal => Ok(candidates.values().next().unwrap().clone()),
         }
@@ -1477,6 +1479,7 @@ impl TraitMap {
                         type_id: type_id.index(),
                         ty: engines.help_out(type_id).to_string(),
                         trait_name: format!("{}{}", trait_name, type_arguments_string),
+                        implemented_for: self.get_implementing_types(engines, trait_name),
                         span: access_span.clone(),
                     });
                 }
@@ -1486,6 +1489,26 @@ impl TraitMap {
         })
     }
 
+    /// Returns the name of every type for which a trait named `trait_name` is
+    /// implemented in this map. The names are sorted and deduplicated, ready
+    /// to be listed in diagnostics.
+    pub(crate) fn get_implementing_types(
+        &self,
+        engines: &Engines,
+        trait_name: &BaseIdent,
+    ) -> Vec<String> {
+        let mut implemented_for = self
+            .trait_impls
+            .values()
+            .flat_map(|impls| impls.iter())
+            .filter(|entry| entry.key.name.suffix.name == *trait_name)
+            .map(|entry| engines.help_out(entry.key.type_id).to_string())
+            .collect::<Vec<_>>();
+        implemented_for.sort();
+        implemented_for.dedup();
+        implemented_for
+    }
+
     fn get_impls_mut(&mut self, engines: &Engines, type_id: TypeId) -> &mut im::Vector<TraitEntry> {
         let type_root_filter = Self::get_type_root_filter(engines, type_id);
         if !self.trait_impls.contains_key(&type_root_filter) {
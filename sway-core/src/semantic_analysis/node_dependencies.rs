@@ -16,8 +16,13 @@ use sway_types::{ident::Ident, span::Span};
 // -------------------------------------------------------------------------------------------------
 /// Take a list of nodes and reorder them so that they may be semantically analysed without any
 /// dependencies breaking.
+///
+/// The ordering is deterministic: dependency resolution uses a stable insertion
+/// sort over the original node order, so equal inputs always produce equal
+/// output orders. Besides driving type checking, this is exposed for tools that
+/// need a reproducible declaration order with dependencies resolved.
 
-pub(crate) fn order_ast_nodes_by_dependency(
+pub fn order_ast_nodes_by_dependency(
     handler: &Handler,
     engines: &Engines,
     nodes: Vec<AstNode>,
@@ -52,6 +57,28 @@ pub(crate) fn order_ast_nodes_by_dependency(
         }))
 }
 
+/// For each declaration in `nodes`, computes the names of the declarations it
+/// directly depends on: types referenced and functions called. The names are
+/// the same ones used for the dependency ordering in
+/// [order_ast_nodes_by_dependency], so tools can reconstruct the dependency
+/// graph that drives it.
+pub fn decl_dependencies(engines: &Engines, nodes: &[AstNode]) -> HashMap<String, HashSet<String>> {
+    nodes
+        .iter()
+        .filter_map(|node| Dependencies::gather_from_decl_node(engines, node))
+        .map(|(name, dependencies)| {
+            (
+                name.display_name(),
+                dependencies
+                    .deps
+                    .iter()
+                    .map(|dep| dep.display_name())
+                    .collect(),
+            )
+        })
+        .collect()
+}
+
 // -------------------------------------------------------------------------------------------------
 // Recursion detection.
 
@@ -847,6 +874,19 @@ enum DependentSymbol {
     Impl(Ident, String, String), // Trait or self, type implementing for, and method names concatenated.
 }
 
+impl DependentSymbol {
+    /// The name under which the symbol is reported by [decl_dependencies].
+    fn display_name(&self) -> String {
+        match self {
+            DependentSymbol::Symbol(name) => name.to_string(),
+            DependentSymbol::Fn(name, _) => name.to_string(),
+            DependentSymbol::Impl(trait_name, type_name, _) => {
+                format!("impl {trait_name} for {type_name}")
+            }
+        }
+    }
+}
+
 // We'll use a custom Hash and PartialEq here to explicitly ignore the span in the Fn variant.
 
 impl PartialEq for DependentSymbol {
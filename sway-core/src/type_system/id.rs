@@ -576,6 +576,22 @@ impl TypeId {
                         // Retrieve the implemented traits for the type and insert them in the namespace.
                         // insert_trait_implementation_for_type is done lazily only when required because of a failure.
                         ctx.insert_trait_implementation_for_type(*structure_type_id);
+                        // Collected up front; the trait map cannot be accessed
+                        // from within the error reporting closure below.
+                        let implemented_for: IndexMap<BaseIdent, Vec<String>> =
+                            structure_trait_constraints
+                                .iter()
+                                .map(|c| {
+                                    (
+                                        c.trait_name.suffix.clone(),
+                                        ctx.namespace()
+                                            .module(engines)
+                                            .current_items()
+                                            .implemented_traits
+                                            .get_implementing_types(engines, &c.trait_name.suffix),
+                                    )
+                                })
+                                .collect();
                         self.check_trait_constraints_errors(
                             handler,
                             ctx.by_ref(),
@@ -599,6 +615,10 @@ impl TypeId {
                                         structure_trait_constraint.trait_name.suffix,
                                         type_arguments_string
                                     ),
+                                    implemented_for: implemented_for
+                                        .get(&structure_trait_constraint.trait_name.suffix)
+                                        .cloned()
+                                        .unwrap_or_default(),
                                     span: span.clone(),
                                 });
                             },
@@ -246,12 +246,17 @@ pub enum CompileError {
         expected: String,
     },
     #[error("Trait \"{name}\" cannot be found in the current scope.")]
-    UnknownTrait { span: Span, name: Ident },
+    UnknownTrait {
+        span: Span,
+        name: Ident,
+        did_you_mean: Option<String>,
+    },
     #[error("Function \"{name}\" is not a part of {interface_name}'s interface surface.")]
     FunctionNotAPartOfInterfaceSurface {
         name: Ident,
         interface_name: InterfaceName,
         span: Span,
+        did_you_mean: Option<String>,
     },
     #[error("Constant \"{name}\" is not a part of {interface_name}'s interface surface.")]
     ConstantNotAPartOfInterfaceSurface {
@@ -428,7 +433,16 @@ pub enum CompileError {
     #[error("This is a {actually}, not a type alias")]
     DeclIsNotATypeAlias { actually: String, span: Span },
     #[error("Could not find symbol \"{name}\" in this scope.")]
-    SymbolNotFound { name: Ident, span: Span },
+    SymbolNotFound {
+        name: Ident,
+        span: Span,
+        /// The name of a symbol in scope closest to `name`,
+        /// if a sufficiently similar one exists.
+        did_you_mean: Option<String>,
+        /// Full paths of modules that declare a symbol named `name`,
+        /// suggested as `use` candidates.
+        import_candidates: Vec<String>,
+    },
     #[error("Found multiple bindings for \"{name}\" in this scope.")]
     SymbolWithMultipleBindings {
         name: Ident,
@@ -500,6 +514,9 @@ pub enum CompileError {
         type_id: usize, // Used to filter errors in method application type check.
         ty: String,
         trait_name: String,
+        /// Names of the types for which the trait _is_ implemented,
+        /// to be listed in the diagnostic.
+        implemented_for: Vec<String>,
         span: Span,
     },
     #[error(
@@ -594,6 +611,12 @@ pub enum CompileError {
     MissingParenthesesForFunction { span: Span, method_name: Ident },
     #[error("This type is invalid in a function selector. A contract ABI function selector must be a known sized type, not generic.")]
     InvalidAbiType { span: Span },
+    #[error("ABI method \"{method_name}\" has the same four-byte function selector as ABI method \"{other_method_name}\". One of the two methods would be unreachable at dispatch time. Rename one of the methods or change its parameter types.")]
+    FunctionSelectorClash {
+        method_name: Ident,
+        other_method_name: Ident,
+        span: Span,
+    },
     #[error("This is a {actually_is}, not an ABI. An ABI cast requires a valid ABI to cast the address to.")]
     NotAnAbi {
         span: Span,
@@ -1129,6 +1152,7 @@ impl Spanned for CompileError {
             TooFewArgumentsForFunction { span, .. } => span.clone(),
             MissingParenthesesForFunction { span, .. } => span.clone(),
             InvalidAbiType { span, .. } => span.clone(),
+            FunctionSelectorClash { span, .. } => span.clone(),
             NotAnAbi { span, .. } => span.clone(),
             ImplAbiForNonContract { span, .. } => span.clone(),
             ConflictingImplsForTraitAndType {
@@ -1740,6 +1764,118 @@ impl ToDiagnostic for CompileError {
                     },
                 }
             },
+            TypeError(type_error @ crate::type_error::TypeError::MismatchedType { expected, received, span, .. }) => {
+                // For complex type names that are mostly equal, pinpoint
+                // the structural difference instead of leaving the reader
+                // to compare the full names character by character.
+                let diff_hint = types_diff(expected, received)
+                    .filter(|(expected_diff, received_diff)| {
+                        !expected_diff.is_empty() && !received_diff.is_empty()
+                    })
+                    .map(|(expected_diff, received_diff)| Hint::info(
+                        source_engine,
+                        span.clone(),
+                        format!("The type names differ here: expected \"{expected_diff}\", found \"{received_diff}\".")
+                    ));
+                Diagnostic {
+                    reason: diff_hint.is_some().then(||
+                        Reason::new(Code::type_checking(1), "Types are mismatched".to_string())),
+                    // Keep the exact wording of the original error in the issue;
+                    // plenty of tooling and test expectations match on it.
+                    issue: Issue::error(
+                        source_engine,
+                        span.clone(),
+                        type_error.to_string()
+                    ),
+                    hints: diff_hint.into_iter().collect(),
+                    help: vec![],
+                }
+            },
+            SymbolNotFound { name, span, did_you_mean, import_candidates } => Diagnostic {
+                reason: Some(Reason::new(code(1), "Symbol is not found in scope".to_string())),
+                issue: Issue::error(
+                    source_engine,
+                    span.clone(),
+                    format!("Could not find symbol \"{name}\" in this scope.")
+                ),
+                hints: did_you_mean
+                    .iter()
+                    .map(|suggestion| Hint::help(
+                        source_engine,
+                        span.clone(),
+                        format!("Did you mean \"{suggestion}\"?")
+                    ))
+                    .collect(),
+                help: import_candidates
+                    .iter()
+                    .map(|candidate| format!("Consider importing it: `use {candidate};`."))
+                    .collect(),
+            },
+            UnknownTrait { span, name, did_you_mean } => Diagnostic {
+                // Render the plain error if there is no suggestion to show.
+                reason: did_you_mean.is_some().then(||
+                    Reason::new(code(1), "Trait is not found in scope".to_string())),
+                issue: Issue::error(
+                    source_engine,
+                    span.clone(),
+                    format!("Trait \"{name}\" cannot be found in the current scope.")
+                ),
+                hints: did_you_mean
+                    .iter()
+                    .map(|suggestion| Hint::help(
+                        source_engine,
+                        span.clone(),
+                        format!("Did you mean \"{suggestion}\"?")
+                    ))
+                    .collect(),
+                help: vec![],
+            },
+            FunctionNotAPartOfInterfaceSurface { name, interface_name, span, did_you_mean } => Diagnostic {
+                // Render the plain error if there is no suggestion to show.
+                reason: did_you_mean.is_some().then(||
+                    Reason::new(code(1), "Function is not a part of interface surface".to_string())),
+                issue: Issue::error(
+                    source_engine,
+                    span.clone(),
+                    format!("Function \"{name}\" is not a part of {interface_name}'s interface surface.")
+                ),
+                hints: did_you_mean
+                    .iter()
+                    .map(|suggestion| Hint::help(
+                        source_engine,
+                        span.clone(),
+                        format!("Did you mean \"{suggestion}\"?")
+                    ))
+                    .collect(),
+                help: vec![],
+            },
+            TraitConstraintNotSatisfied { ty, trait_name, implemented_for, span, .. } => Diagnostic {
+                reason: Some(Reason::new(code(1), "Trait constraint is not satisfied".to_string())),
+                issue: Issue::error(
+                    source_engine,
+                    span.clone(),
+                    format!("Trait \"{trait_name}\" is not implemented for type \"{ty}\".")
+                ),
+                hints: vec![
+                    Hint::help(
+                        source_engine,
+                        span.clone(),
+                        format!("This code requires \"{ty}\" to satisfy the trait constraint \"{trait_name}\".")
+                    ),
+                ],
+                help: {
+                    let mut help = vec![];
+                    if !implemented_for.is_empty() {
+                        help.push(format!(
+                            "In the current scope, \"{trait_name}\" is implemented for {}.",
+                            sequence_to_str(implemented_for, Enclosing::DoubleQuote, 5)
+                        ));
+                    }
+                    help.push(format!("Consider implementing \"{trait_name}\" for \"{ty}\"."));
+                    help.push(format!("Alternatively, if an implementation of \"{trait_name}\" for \"{ty}\" already exists in a library, import it with a `use` statement."));
+                    help
+                },
+            },
             // TODO-IG: Extend error messages to pointers, once typed pointers are defined and can be dereferenced.
             ExpressionCannotBeDereferenced { expression_type, span } => Diagnostic {
                 reason: Some(Reason::new(code(1), "Expression cannot be dereferenced".to_string())),
@@ -362,3 +362,51 @@ where
         .map(|(_, pv)| pv)
         .collect()
 }
+
+/// Returns the differing middle parts of two type names, i.e. what remains
+/// after stripping their longest common prefix and suffix, snapped to type
+/// syntax boundaries so that identifiers are never cut in half.
+///
+/// Returns `None` if the names are equal, or if they have nothing in common,
+/// in which case showing a diff has no value over showing the full names.
+pub(crate) fn types_diff(expected: &str, received: &str) -> Option<(String, String)> {
+    const SEPARATORS: [char; 7] = ['<', '>', '(', ')', '[', ']', ','];
+
+    if expected == received {
+        return None;
+    }
+
+    let expected: Vec<char> = expected.chars().collect();
+    let received: Vec<char> = received.chars().collect();
+
+    let mut prefix = 0;
+    while prefix < expected.len() && prefix < received.len() && expected[prefix] == received[prefix]
+    {
+        prefix += 1;
+    }
+    let mut suffix = 0;
+    while suffix < expected.len() - prefix
+        && suffix < received.len() - prefix
+        && expected[expected.len() - 1 - suffix] == received[received.len() - 1 - suffix]
+    {
+        suffix += 1;
+    }
+
+    // Snap to separators so that, e.g., diffing "Vec<u64>" and "Vec<u32>"
+    // yields "u64"/"u32" and not "4"/"2".
+    while prefix > 0 && !SEPARATORS.contains(&expected[prefix - 1]) {
+        prefix -= 1;
+    }
+    while suffix > 0 && !SEPARATORS.contains(&expected[expected.len() - suffix]) {
+        suffix -= 1;
+    }
+
+    if prefix == 0 && suffix == 0 {
+        return None;
+    }
+
+    Some((
+        expected[prefix..expected.len() - suffix].iter().collect(),
+        received[prefix..received.len() - suffix].iter().collect(),
+    ))
+}
@@ -28,35 +28,43 @@ impl Parse for ModuleKind {
 
 impl ParseToEnd for Annotated<Module> {
     fn parse_to_end<'a, 'e>(mut parser: Parser<'a, '_>) -> ParseResult<(Self, ParserConsumed<'a>)> {
-        // Parse the attribute list.
+        // Parse the attribute list. A module is annotated by inner doc
+        // comments (`//!`) and inner attributes (`#![...]`) preceding the
+        // module kind.
         let mut attribute_list = Vec::new();
-        while let Some(DocComment { .. }) = parser.peek() {
-            let doc_comment = parser.parse::<DocComment>()?;
-            // TODO: Use a Literal instead of an Ident when Attribute args
-            // start supporting them and remove `Ident::new_no_trim`.
-            let name = Ident::new_no_trim(doc_comment.content_span.clone());
-            match &doc_comment.doc_style {
-                DocStyle::Inner => attribute_list.push(AttributeDecl {
-                    hash_kind: AttributeHashKind::Inner(HashBangToken::new(
-                        doc_comment.span.clone(),
-                    )),
-                    attribute: SquareBrackets::new(
-                        Punctuated::single(Attribute {
-                            name: Ident::new_with_override(
-                                DOC_COMMENT_ATTRIBUTE_NAME.to_string(),
-                                doc_comment.span.clone(),
-                            ),
-                            args: Some(Parens::new(
-                                Punctuated::single(AttributeArg { name, value: None }),
-                                doc_comment.content_span,
-                            )),
-                        }),
-                        doc_comment.span,
-                    ),
-                }),
-                DocStyle::Outer => {
-                    parser.emit_error(ParseErrorKind::ExpectedModuleDocComment);
+        loop {
+            if let Some(DocComment { .. }) = parser.peek() {
+                let doc_comment = parser.parse::<DocComment>()?;
+                // TODO: Use a Literal instead of an Ident when Attribute args
+                // start supporting them and remove `Ident::new_no_trim`.
+                let name = Ident::new_no_trim(doc_comment.content_span.clone());
+                match &doc_comment.doc_style {
+                    DocStyle::Inner => attribute_list.push(AttributeDecl {
+                        hash_kind: AttributeHashKind::Inner(HashBangToken::new(
+                            doc_comment.span.clone(),
+                        )),
+                        attribute: SquareBrackets::new(
+                            Punctuated::single(Attribute {
+                                name: Ident::new_with_override(
+                                    DOC_COMMENT_ATTRIBUTE_NAME.to_string(),
+                                    doc_comment.span.clone(),
+                                ),
+                                args: Some(Parens::new(
+                                    Punctuated::single(AttributeArg { name, value: None }),
+                                    doc_comment.content_span,
+                                )),
+                            }),
+                            doc_comment.span,
+                        ),
+                    }),
+                    DocStyle::Outer => {
+                        parser.emit_error(ParseErrorKind::ExpectedModuleDocComment);
+                    }
                 }
+            } else if let Some(attr) = parser.guarded_parse::<HashBangToken, AttributeDecl>()? {
+                attribute_list.push(attr);
+            } else {
+                break;
             }
         }
         let (kind, semicolon_token) = parser.parse()?;
@@ -14,5 +14,9 @@ pub const STRUCT_FIELD_SEPARATOR: &str = ".";
 pub const LIB_ENTRY: &str = "lib.sw";
 pub const MAIN_ENTRY: &str = "main.sw";
 pub const FORC_INIT_MANIFEST_AUTHOR: &str = "FORC_INIT_MANIFEST_AUTHOR";
+/// Environment variable that controls how compiler diagnostics are printed.
+/// Setting it to `json` makes every diagnostic a newline-delimited JSON object
+/// instead of the human-readable rendering. Any other value is ignored.
+pub const FORC_DIAGNOSTICS_FORMAT: &str = "FORC_DIAGNOSTICS_FORMAT";
 // TODO: Once Fuel has its own IPFS node, default should be that node for performance reasons.
 pub const DEFAULT_IPFS_GATEWAY_URL: &str = "https://ipfs.io";
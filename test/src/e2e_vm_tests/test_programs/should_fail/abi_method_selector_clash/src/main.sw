@@ -0,0 +1,19 @@
+contract;
+
+// The two method names below were brute-forced so that
+// sha256("clash_7451()") and sha256("clash_8716()") share the same
+// first four bytes (0x0571da48), i.e. the same encoding v0 selector.
+abi SelectorClash {
+    fn clash_7451() -> u64;
+    fn clash_8716() -> u64;
+}
+
+impl SelectorClash for Contract {
+    fn clash_7451() -> u64 {
+        1
+    }
+
+    fn clash_8716() -> u64 {
+        2
+    }
+}
@@ -0,0 +1,8 @@
+script;
+
+fn main() -> u64 {
+    asm(r1: 1, r2: 2, result) {
+        add result r1 r3;
+        result: u64
+    }
+}
@@ -0,0 +1,9 @@
+script;
+
+fn do_something() -> u64 {
+    42
+}
+
+fn main() -> u64 {
+    do_somthing()
+}
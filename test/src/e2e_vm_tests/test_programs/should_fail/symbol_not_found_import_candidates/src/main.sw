@@ -0,0 +1,9 @@
+script;
+
+mod utils;
+
+fn main() -> u64 {
+    let a = helper();
+    let b = internal();
+    a + b
+}
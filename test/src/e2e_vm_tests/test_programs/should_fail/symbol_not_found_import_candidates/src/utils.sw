@@ -0,0 +1,9 @@
+library;
+
+pub fn helper() -> u64 {
+    7
+}
+
+fn internal() -> u64 {
+    11
+}
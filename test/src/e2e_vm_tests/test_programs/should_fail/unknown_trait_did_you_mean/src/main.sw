@@ -0,0 +1,26 @@
+script;
+
+trait MyTrait {
+    fn do_it(self) -> u64;
+}
+
+struct Foo {}
+struct Bar {}
+
+// Misspelled trait name.
+impl MyTrate for Foo {
+    fn do_it(self) -> u64 {
+        1
+    }
+}
+
+// Misspelled interface method name.
+impl MyTrait for Bar {
+    fn do_itt(self) -> u64 {
+        2
+    }
+}
+
+fn main() -> u64 {
+    42
+}
@@ -0,0 +1,25 @@
+contract;
+
+struct S {
+    x: u64,
+}
+
+impl S {
+    fn used(self) -> u64 {
+        self.x
+    }
+
+    fn unused(self) -> u64 {
+        0
+    }
+}
+
+abi MyContract {
+    fn get_x() -> u64;
+}
+
+impl MyContract for Contract {
+    fn get_x() -> u64 {
+        S { x: 42 }.used()
+    }
+}
@@ -0,0 +1,7 @@
+library;
+
+mod old_api;
+
+pub fn f() -> u64 {
+    64
+}
@@ -0,0 +1,6 @@
+#![deprecated(note = "use the new API instead")]
+library;
+
+pub fn old() -> u64 {
+    0
+}